@@ -5,12 +5,11 @@ macro_rules! assert_eqf64 {
             let (a, b) = (&$a, &$b); // references just for easy typing below (no $)
 
             let delta = (a - b).abs();
-            let eps = f64::EPSILON; // if we used our own epsilon we could make this work for f32 too
 
             assert!(
-                delta < eps,
-                "Equality check failed, {} != {}, difference was {} which is larger than tolerance of {}",
-                a, b, delta, eps
+                $crate::eqf64::eq_f64(*a, *b),
+                "Equality check failed, {} != {}, difference was {}",
+                a, b, delta
             )
         }};
     }
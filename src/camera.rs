@@ -0,0 +1,133 @@
+use crate::matrix::Matrix;
+use crate::ray::Ray;
+use crate::tuple::Tuple;
+
+/// A pinhole camera posed in the world by `transform` (built with `Matrix::view_transform`),
+/// looking down -z towards a virtual canvas of `hsize` by `vsize` pixels spanning
+/// `field_of_view` radians.
+#[derive(Debug, Clone)]
+pub struct Camera {
+    pub hsize: usize,
+    pub vsize: usize,
+    pub field_of_view: f64,
+    pub transform: Matrix,
+    half_width: f64,
+    half_height: f64,
+    pixel_size: f64,
+}
+
+impl Camera {
+    pub fn new(hsize: usize, vsize: usize, field_of_view: f64) -> Camera {
+        let half_view = (field_of_view / 2.0).tan();
+        let aspect = hsize as f64 / vsize as f64;
+
+        let (half_width, half_height) = if aspect >= 1.0 {
+            (half_view, half_view / aspect)
+        } else {
+            (half_view * aspect, half_view)
+        };
+
+        let pixel_size = (half_width * 2.0) / hsize as f64;
+
+        Camera {
+            hsize,
+            vsize,
+            field_of_view,
+            transform: Matrix::transformation(),
+            half_width,
+            half_height,
+            pixel_size,
+        }
+    }
+
+    pub fn pixel_size(&self) -> f64 {
+        self.pixel_size
+    }
+
+    pub fn set_transform(&mut self, transform: Matrix) {
+        self.transform = transform;
+    }
+
+    /// Casts a world-space ray from the camera through the center of pixel `(px, py)`.
+    pub fn ray_for_pixel(&self, px: usize, py: usize) -> Ray {
+        // offset from the edge of the canvas to the pixel's center
+        let xoffset = (px as f64 + 0.5) * self.pixel_size;
+        let yoffset = (py as f64 + 0.5) * self.pixel_size;
+
+        // untransformed coordinates of the pixel in camera space (camera looks toward -z, +x is left)
+        let world_x = self.half_width - xoffset;
+        let world_y = self.half_height - yoffset;
+
+        let inverse = self.transform.inverse();
+        let pixel = &inverse * &Tuple::point(world_x, world_y, -1.0);
+        let origin = &inverse * &Tuple::origin();
+        let direction = (pixel - origin).normalized();
+
+        Ray::new(origin, direction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use crate::assert_eqf64;
+    use crate::tuple::{point, vector};
+
+    use super::*;
+
+    #[test]
+    fn constructing_a_camera() {
+        let hsize = 160;
+        let vsize = 120;
+        let field_of_view = PI / 2.0;
+
+        let c = Camera::new(hsize, vsize, field_of_view);
+
+        assert_eq!(c.hsize, 160);
+        assert_eq!(c.vsize, 120);
+        assert_eqf64!(c.field_of_view, PI / 2.0);
+        assert_eq!(c.transform, Matrix::identity(4));
+    }
+
+    #[test]
+    fn the_pixel_size_for_a_horizontal_canvas() {
+        let c = Camera::new(200, 125, PI / 2.0);
+        assert_eqf64!(c.pixel_size(), 0.01);
+    }
+
+    #[test]
+    fn the_pixel_size_for_a_vertical_canvas() {
+        let c = Camera::new(125, 200, PI / 2.0);
+        assert_eqf64!(c.pixel_size(), 0.01);
+    }
+
+    #[test]
+    fn constructing_a_ray_through_the_center_of_the_canvas() {
+        let c = Camera::new(201, 101, PI / 2.0);
+        let r = c.ray_for_pixel(100, 50);
+
+        assert_eq!(r.origin, point(0, 0, 0));
+        assert_eq!(r.direction, vector(0, 0, -1));
+    }
+
+    #[test]
+    fn constructing_a_ray_through_a_corner_of_the_canvas() {
+        let c = Camera::new(201, 101, PI / 2.0);
+        let r = c.ray_for_pixel(0, 0);
+
+        assert_eq!(r.origin, point(0, 0, 0));
+        assert_eq!(r.direction.round(5), vector(0.66519, 0.33259, -0.66851));
+    }
+
+    #[test]
+    fn constructing_a_ray_when_the_camera_is_transformed() {
+        let mut c = Camera::new(201, 101, PI / 2.0);
+        c.set_transform(Matrix::rotation_y(PI / 4.0) * Matrix::translation(0., -2., 5.));
+        let r = c.ray_for_pixel(100, 50);
+
+        let rt = 2_f64.sqrt() / 2.0;
+        assert_eq!(r.origin, point(0, 2, -5));
+        assert_eq!(r.direction.round(5), vector(rt, 0, -rt).round(5));
+    }
+}
@@ -2,8 +2,33 @@ use std::fs::File;
 use std::io::Write;
 use std::path::Path;
 
+use rayon::prelude::*;
+
 use crate::color::Color;
 
+/// Which PPM variant `Canvas::save_to_disk_as` should write: human-readable ASCII (`P3`) or the
+/// smaller, faster-to-write raw-byte binary form (`P6`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PpmFormat {
+    Ascii,
+    Binary,
+}
+
+/// Why `Canvas::from_ppm` gave up parsing a P3 PPM document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PpmError {
+    /// The first token wasn't `P3` (or was missing entirely).
+    BadMagic,
+    /// `width`/`height` were missing or not a valid `usize`.
+    BadDimensions,
+    /// The max color value was missing, not a valid number, or zero.
+    BadMaxValue,
+    /// A pixel-data token wasn't a valid integer in the PPM channel range (0 to 65535).
+    BadToken(String),
+    /// The number of pixel-data tokens didn't match `width * height * 3`.
+    WrongTokenCount { expected: usize, found: usize },
+}
+
 pub struct Canvas {
     pub width: usize,
     pub height: usize,
@@ -47,42 +72,172 @@ impl Canvas {
         self.pixels[self.index(x, y)]
     }
 
-    pub fn render_as_ppm(&self) -> String {
+    /// Fills every pixel by calling `f(x, y)` in parallel across the backing buffer. Since
+    /// `pixels` is stored one column at a time (see `index`), chunking by `height` hands each
+    /// rayon worker a contiguous, non-overlapping column to write into without any locking.
+    pub fn render_parallel<F: Fn(usize, usize) -> Color + Sync>(&mut self, f: F) {
+        let height = self.height;
+        self.pixels
+            .par_chunks_mut(height)
+            .enumerate()
+            .for_each(|(x, column)| {
+                for (y, pixel) in column.iter_mut().enumerate() {
+                    *pixel = f(x, y);
+                }
+            });
+    }
+
+    /// Same as `render_parallel`, but runs on a dedicated rayon thread pool of `threads` workers
+    /// instead of the global one, for callers that want to bound how much of the machine a single
+    /// render uses.
+    pub fn render_parallel_with_threads<F: Fn(usize, usize) -> Color + Sync + Send>(
+        &mut self,
+        threads: usize,
+        f: F,
+    ) {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .expect("failed to build thread pool");
+        pool.install(|| self.render_parallel(f));
+    }
+
+    /// Renders one canvas row's PPM pixel data (space-separated channel values, wrapped onto
+    /// as many output lines as needed so none exceeds 70 columns), shared by `render_as_ppm` and `render_as_ppm_parallel`.
+    fn render_row_as_ppm(&self, y: usize) -> String {
         let newline = "\n";
+        let max_line_length = 70;
 
-        // rough estimate of capacity needed to render the whole canvas to PPM
-        let mut s = String::with_capacity(self.width * self.height * 3 * 2 + 10);
+        let mut s = String::new();
+        let mut line_length = 0;
 
-        // header
-        s.push_str("P3");
+        for x in 0..self.width {
+            let color = self.pixel_at(x, y);
+            let color_str: String = color.render_as_ppm();
+            if line_length + color_str.len() > max_line_length {
+                s.push_str(newline);
+                line_length = 0;
+            }
+            line_length += color_str.len();
+            s.push_str(color_str.as_str());
+        }
         s.push_str(newline);
-        s.push_str(format!("{} {}{}", self.width, self.height, newline).as_str());
-        s.push_str(format!("255{}", newline).as_str());
 
-        // pixel data
+        s
+    }
+
+    fn ppm_header(&self) -> String {
+        format!("P3\n{} {}\n255\n", self.width, self.height)
+    }
+
+    pub fn render_as_ppm(&self) -> String {
+        // rough estimate of capacity needed to render the whole canvas to PPM
+        let mut s = String::with_capacity(self.width * self.height * 3 * 2 + 10);
+        s.push_str(&self.ppm_header());
+
         for y in 0..self.height {
-            let max_line_length = 70;
-            let mut line_length = 0;
+            s.push_str(&self.render_row_as_ppm(y));
+        }
+
+        s
+    }
+
+    /// Same output as `render_as_ppm`, but formats each row's PPM text independently in parallel
+    /// (rows are read-only here, so there's no aliasing to worry about) before concatenating them
+    /// in order.
+    pub fn render_as_ppm_parallel(&self) -> String {
+        let rows: String = (0..self.height)
+            .into_par_iter()
+            .map(|y| self.render_row_as_ppm(y))
+            .collect::<Vec<_>>()
+            .concat();
+
+        self.ppm_header() + &rows
+    }
+
+    /// Renders a `P6` binary PPM: the same header layout as `render_as_ppm` but with the `P6`
+    /// magic number, followed by one raw byte
+    /// per channel per pixel (no whitespace, no line-length limit needed).
+    pub fn render_as_ppm_binary(&self) -> Vec<u8> {
+        let mut bytes = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        bytes.reserve(self.width * self.height * 3);
 
+        for y in 0..self.height {
             for x in 0..self.width {
-                let color = self.pixel_at(x, y);
-                let color_str: String = color.render_as_ppm();
-                if line_length + color_str.len() > max_line_length {
-                    s.push_str(newline);
-                    line_length = 0;
-                }
-                line_length += color_str.len();
-                s.push_str(color_str.as_str());
+                bytes.extend_from_slice(&self.pixel_at(x, y).to_rgb_bytes());
             }
-            s.push_str(newline)
         }
 
-        // return s
-        s
+        bytes
+    }
+
+    /// Parses a P3 (ASCII) PPM document, the inverse of `render_as_ppm`. Lines in the input may
+    /// be 70-column wrapped or not at all, since tokens are read as one whitespace-separated
+    /// stream rather than line-by-line; `#` starts a comment that runs to the end of its line.
+    pub fn from_ppm(text: &str) -> Result<Canvas, PpmError> {
+        let uncommented: String = text
+            .lines()
+            .map(|line| match line.find('#') {
+                Some(i) => &line[..i],
+                None => line,
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let mut tokens = uncommented.split_whitespace();
+
+        let magic = tokens.next().ok_or(PpmError::BadMagic)?;
+        if magic != "P3" {
+            return Err(PpmError::BadMagic);
+        }
+
+        let mut dimension = || tokens.next().and_then(|t| t.parse::<usize>().ok());
+        let width = dimension().ok_or(PpmError::BadDimensions)?;
+        let height = dimension().ok_or(PpmError::BadDimensions)?;
+
+        let max_value = tokens
+            .next()
+            .and_then(|t| t.parse::<f64>().ok())
+            .filter(|&v| v > 0.0)
+            .ok_or(PpmError::BadMaxValue)?;
+
+        let values: Vec<u16> = tokens
+            .map(|t| t.parse::<u16>().map_err(|_| PpmError::BadToken(t.to_string())))
+            .collect::<Result<_, _>>()?;
+
+        let expected = width * height * 3;
+        if values.len() != expected {
+            return Err(PpmError::WrongTokenCount {
+                expected,
+                found: values.len(),
+            });
+        }
+
+        let mut canvas = Canvas::new(width, height);
+        for y in 0..height {
+            for x in 0..width {
+                let base = (y * width + x) * 3;
+                let color = Color::rgb(
+                    values[base] as f64 / max_value,
+                    values[base + 1] as f64 / max_value,
+                    values[base + 2] as f64 / max_value,
+                );
+                canvas.write_pixel(x, y, color);
+            }
+        }
+
+        Ok(canvas)
     }
 
     pub fn save_to_disk(&self, location: &str) {
-        let ppm = self.render_as_ppm();
+        self.save_to_disk_as(location, PpmFormat::Ascii)
+    }
+
+    pub fn save_to_disk_as(&self, location: &str, format: PpmFormat) {
+        let bytes = match format {
+            PpmFormat::Ascii => self.render_as_ppm().into_bytes(),
+            PpmFormat::Binary => self.render_as_ppm_binary(),
+        };
 
         let path = Path::new(location);
         let display = path.display();
@@ -91,7 +246,7 @@ impl Canvas {
             Ok(file) => file,
         };
 
-        match file.write_all(ppm.as_bytes()) {
+        match file.write_all(&bytes) {
             Err(why) => panic!("couldn't write to {}: {}", display, why),
             Ok(_) => println!("successfully wrote output to {}", display),
         };
@@ -195,4 +350,146 @@ mod tests {
 
         assert_eq!(last, '\n');
     }
+
+    #[test]
+    fn test_render_parallel_matches_serial_fill() {
+        let shade = |x: usize, y: usize| Color::rgb(x as f64, y as f64, (x + y) as f64);
+
+        let mut serial = Canvas::new(9, 7);
+        for x in 0..serial.width {
+            for y in 0..serial.height {
+                serial.write_pixel(x, y, shade(x, y));
+            }
+        }
+
+        let mut parallel = Canvas::new(9, 7);
+        parallel.render_parallel(shade);
+
+        for x in 0..parallel.width {
+            for y in 0..parallel.height {
+                assert_eq!(parallel.pixel_at(x, y), serial.pixel_at(x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn test_render_parallel_computes_every_pixel_exactly_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = AtomicUsize::new(0);
+        let mut canvas = Canvas::new(13, 11);
+        canvas.render_parallel(|_x, _y| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Color::rgb(0., 0., 0.)
+        });
+
+        assert_eq!(calls.load(Ordering::SeqCst), canvas.width * canvas.height);
+    }
+
+    #[test]
+    fn test_render_parallel_with_threads_matches_serial_fill() {
+        let shade = |x: usize, y: usize| Color::rgb(x as f64, y as f64, (x + y) as f64);
+
+        let mut serial = Canvas::new(9, 7);
+        for x in 0..serial.width {
+            for y in 0..serial.height {
+                serial.write_pixel(x, y, shade(x, y));
+            }
+        }
+
+        let mut parallel = Canvas::new(9, 7);
+        parallel.render_parallel_with_threads(2, shade);
+
+        for x in 0..parallel.width {
+            for y in 0..parallel.height {
+                assert_eq!(parallel.pixel_at(x, y), serial.pixel_at(x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn test_render_as_ppm_parallel_matches_serial() {
+        let mut c = Canvas::new(9, 7);
+        c.fill(Color::rgb(0.1, 0.5, 0.9));
+        c.write_pixel(3, 4, Color::RED);
+
+        assert_eq!(c.render_as_ppm_parallel(), c.render_as_ppm());
+    }
+
+    #[test]
+    fn from_ppm_round_trips_through_render_as_ppm() {
+        use crate::eqf64::eq_f64_tolerance;
+
+        let mut original = Canvas::new(5, 3);
+        original.fill(Color::rgb(0.2, 0.4, 0.6));
+        original.write_pixel(0, 0, Color::RED);
+        original.write_pixel(4, 2, Color::rgb(0.1, 0.9, 0.3));
+
+        let parsed = Canvas::from_ppm(&original.render_as_ppm()).unwrap();
+
+        assert_eq!(parsed.width, original.width);
+        assert_eq!(parsed.height, original.height);
+
+        // quantized to 8-bit channels by render_as_ppm, so compare with a byte-sized tolerance
+        // rather than eq_f64's default (built for exact-ish floating point arithmetic, not
+        // lossy 0-255 round-tripping)
+        let channel_tolerance = 1.0 / 255.0;
+        for x in 0..original.width {
+            for y in 0..original.height {
+                let (a, b) = (parsed.pixel_at(x, y), original.pixel_at(x, y));
+                assert!(eq_f64_tolerance(a.red, b.red, channel_tolerance, 0.0, 0));
+                assert!(eq_f64_tolerance(a.green, b.green, channel_tolerance, 0.0, 0));
+                assert!(eq_f64_tolerance(a.blue, b.blue, channel_tolerance, 0.0, 0));
+            }
+        }
+    }
+
+    #[test]
+    fn from_ppm_tolerates_comments_and_arbitrary_line_wrapping() {
+        let text = "P3\n# a comment\n2 1\n255\n255 0\n0 0 255\n255";
+        let canvas = Canvas::from_ppm(text).unwrap();
+        assert_eq!(canvas.pixel_at(0, 0), Color::rgb(1., 0., 0.));
+        assert_eq!(canvas.pixel_at(1, 0), Color::rgb(0., 1., 1.));
+    }
+
+    #[test]
+    fn from_ppm_rejects_a_bad_magic_number() {
+        match Canvas::from_ppm("P6\n1 1\n255\n0 0 0") {
+            Err(PpmError::BadMagic) => {}
+            other => panic!("expected BadMagic, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn from_ppm_rejects_a_mismatched_token_count() {
+        match Canvas::from_ppm("P3\n1 1\n255\n0 0") {
+            Err(PpmError::WrongTokenCount { expected: 3, found: 2 }) => {}
+            other => panic!("expected WrongTokenCount {{3, 2}}, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn test_binary_ppm_header() {
+        let mut c = Canvas::new(5, 3);
+        c.write_pixel(0, 0, Color::rgb(1., 0., 0.));
+
+        let bytes = c.render_as_ppm_binary();
+        let header_end = bytes
+            .windows(4)
+            .position(|w| w == b"255\n")
+            .map(|i| i + 4)
+            .unwrap();
+
+        assert_eq!(&bytes[..header_end], b"P6\n5 3\n255\n");
+        // one byte per channel per pixel, no separators
+        assert_eq!(bytes.len() - header_end, 5 * 3 * 3);
+        assert_eq!(&bytes[header_end..header_end + 3], &[255, 0, 0]);
+    }
+
+    #[test]
+    fn test_round_half_up_channel_quantization() {
+        // halves round up and anything below a half rounds down (ceil() rounded 0.49 up to 1 too).
+        assert_eq!(Color::rgb(0.5 / 255.0, 0., 0.).to_rgb_bytes(), [1, 0, 0]);
+        assert_eq!(Color::rgb(0.49 / 255.0, 0., 0.).to_rgb_bytes(), [0, 0, 0]);
+    }
 }
@@ -0,0 +1,54 @@
+use crate::canvas::Canvas;
+use crate::challenges::save;
+use crate::color::Color;
+use crate::light::PointLight;
+use crate::material::Material;
+use crate::ray::Ray;
+use crate::shape::{CanIntersect, Shape};
+use crate::sphere::Sphere;
+use crate::tuple::Tuple;
+
+/// Casts one ray per pixel at a unit sphere floating in front of the camera, shades the nearest
+/// hit against a single point light, and writes the result out as a `canvas_size`x`canvas_size` PPM.
+pub fn chapter6_render_shaded_sphere(canvas_size: usize) {
+    let ray_origin = Tuple::point(0., 0., -5.);
+    let wall_z = 10.0;
+    let wall_size = 7.0;
+
+    let pixel_size = wall_size / canvas_size as f64;
+    let half = wall_size / 2.0;
+
+    let mut sphere = Sphere::new();
+    sphere.material = Material {
+        color: Color::rgb(1.0, 0.2, 1.0),
+        ..Material::new()
+    };
+    let shape = Shape::Sphere(sphere);
+
+    let light = PointLight {
+        position: Tuple::point(-10., 10., -10.),
+        intensity: Color::WHITE,
+    };
+
+    let mut canvas = Canvas::new(canvas_size, canvas_size);
+    canvas.render_parallel(|x, y| {
+        let world_x = -half + pixel_size * x as f64;
+        let world_y = half - pixel_size * y as f64;
+        let position = Tuple::point(world_x, world_y, wall_z);
+
+        let ray = Ray::new(ray_origin, (position - ray_origin).normalized());
+        let hit = shape.intersections(ray).hit().map(|i| i.t);
+
+        match hit {
+            Some(t) => {
+                let point = ray.position(t);
+                let eyev = -ray.direction;
+                let normalv = shape.normal_at(point);
+                shape.material().lighting(light, point, eyev, normalv, false)
+            }
+            None => Color::BLACK,
+        }
+    });
+
+    save(&canvas, "ch6_shading_sphere");
+}
@@ -6,7 +6,7 @@ use crate::light::PointLight;
 use crate::material::Material;
 use crate::matrix::Matrix;
 use crate::ray::Ray;
-use crate::shape::{CanIntersect, hit, Intersection, Shape, sphere};
+use crate::shape::{CanIntersect, Intersection, Intersections, Shape, sphere};
 use crate::sphere::Sphere;
 use crate::tuple::point;
 
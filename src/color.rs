@@ -12,6 +12,14 @@ pub struct Color {
 }
 
 impl Color {
+    /// Rounds each channel to `places` decimal places, for comparing computed colors against
+    /// fixtures that only carry a handful of significant digits.
+    pub fn round(&self, places: i32) -> Color {
+        let fac = 10_f64.powi(places);
+        let round = |c: f64| (c * fac).round() / fac;
+        Color::rgb(round(self.red), round(self.green), round(self.blue))
+    }
+
     pub fn clamp(&self) -> Color {
         let (min, max) = (0_f64, 1_f64);
         Color::rgb(
@@ -21,11 +29,18 @@ impl Color {
         )
     }
     pub(crate) fn render_as_ppm(&self) -> String {
+        let [r, g, b] = self.to_rgb_bytes();
+        format!("{} {} {} ", r, g, b)
+    }
+
+    /// This struct's color, as clamped 0-255 RGB bytes, rounding to the nearest byte with halves
+    /// rounding up, so e.g. a scaled value of `127.2` maps to 127 rather than the 128 `ceil()` gives.
+    pub(crate) fn to_rgb_bytes(&self) -> [u8; 3] {
         let clamped = self.clamp();
-        fn rgb(value: f64) -> i32 {
-            (value * 255_f64).ceil() as i32
+        fn channel(value: f64) -> u8 {
+            (value * 255_f64).round() as u8
         }
-        format!("{} {} {} ", rgb(clamped.red), rgb(clamped.green), rgb(clamped.blue))
+        [channel(clamped.red), channel(clamped.green), channel(clamped.blue)]
     }
 }
 
@@ -36,6 +51,18 @@ impl Color {
         blue: 0.0,
     };
 
+    pub const WHITE: Color = Color {
+        red: 1.0,
+        green: 1.0,
+        blue: 1.0,
+    };
+
+    pub const BLACK: Color = Color {
+        red: 0.0,
+        green: 0.0,
+        blue: 0.0,
+    };
+
     pub(crate) fn rgb(r: f64, g: f64, b: f64) -> Color {
         Color {
             red: r,
@@ -120,6 +147,15 @@ impl PartialEq for Color {
     }
 }
 
+pub fn color<R, G, B>(r: R, g: G, b: B) -> Color
+    where
+        R: Into<f64>,
+        G: Into<f64>,
+        B: Into<f64>,
+{
+    Color::rgb(r.into(), g.into(), b.into())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,141 @@
+#[cfg(test)]
+use crate::assert_eqf64;
+use crate::color::Color;
+use crate::light::PointLight;
+use crate::ray::Ray;
+use crate::shape::{CanIntersect, Intersection, Shape};
+use crate::tuple::Tuple;
+
+/// Everything `shade_hit` needs about a single intersection, computed once up front so the
+/// lighting calculation itself doesn't have to re-derive them: the world-space hit point, the
+/// direction back to the eye, and the surface normal. If the ray started inside `object`, `inside`
+/// is set and `normalv` is flipped to keep pointing towards the eye.
+pub struct Computations<'a> {
+    pub t: f64,
+    pub object: &'a Shape,
+    pub point: Tuple,
+    pub eyev: Tuple,
+    pub normalv: Tuple,
+    pub inside: bool,
+}
+
+/// Precomputes the state `shade_hit` needs for `intersection`, given the `ray` that produced it.
+pub fn prepare_computations<'a>(intersection: &Intersection<'a>, ray: Ray) -> Computations<'a> {
+    let point = ray.position(intersection.t);
+    let eyev = -ray.direction;
+    let mut normalv = intersection.object.normal_at(point);
+
+    let inside = normalv.dot(eyev) < 0.0;
+    if inside {
+        normalv = -normalv;
+    }
+
+    Computations {
+        t: intersection.t,
+        object: intersection.object,
+        point,
+        eyev,
+        normalv,
+        inside,
+    }
+}
+
+/// Shades a precomputed hit under a single light, via the object's material's Phong `lighting`.
+pub fn shade_hit(comps: &Computations, light: &PointLight) -> Color {
+    comps
+        .object
+        .material()
+        .lighting(*light, comps.point, comps.eyev, comps.normalv, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tuple::{point, vector};
+
+    use super::*;
+
+    #[test]
+    fn precomputing_the_state_of_an_intersection() {
+        let r = Ray::new(point(0, 0, -5), vector(0, 0, 1));
+        let shape = crate::shape::sphere();
+        let i = Intersection {
+            t: 4.0,
+            object: &shape,
+        };
+
+        let comps = prepare_computations(&i, r);
+
+        assert_eqf64!(comps.t, i.t);
+        assert_eq!(comps.object, i.object);
+        assert_eq!(comps.point, point(0, 0, -1));
+        assert_eq!(comps.eyev, vector(0, 0, -1));
+        assert_eq!(comps.normalv, vector(0, 0, -1));
+        assert!(!comps.inside);
+    }
+
+    #[test]
+    fn the_hit_when_an_intersection_occurs_on_the_inside() {
+        let r = Ray::new(point(0, 0, 0), vector(0, 0, 1));
+        let shape = crate::shape::sphere();
+        let i = Intersection {
+            t: 1.0,
+            object: &shape,
+        };
+
+        let comps = prepare_computations(&i, r);
+
+        assert_eq!(comps.point, point(0, 0, 1));
+        assert_eq!(comps.eyev, vector(0, 0, -1));
+        assert!(comps.inside);
+        // the "real" normal at this point is (0, 0, 1), but gets flipped since we're inside the sphere
+        assert_eq!(comps.normalv, vector(0, 0, -1));
+    }
+
+    #[test]
+    fn shade_hit_matches_calling_the_material_lighting_directly() {
+        let r = Ray::new(point(0, 0, -5), vector(0, 0, 1));
+        let shape = crate::shape::sphere();
+        let i = Intersection {
+            t: 4.0,
+            object: &shape,
+        };
+        let comps = prepare_computations(&i, r);
+
+        let light = PointLight {
+            position: point(-10, 10, -10),
+            intensity: Color::rgb(1., 1., 1.),
+        };
+
+        let expected =
+            shape
+                .material()
+                .lighting(light, comps.point, comps.eyev, comps.normalv, false);
+
+        assert_eq!(shade_hit(&comps, &light), expected);
+    }
+
+    #[test]
+    fn shade_hit_from_the_inside_uses_the_flipped_normal() {
+        let r = Ray::new(point(0, 0, 0), vector(0, 0, 1));
+        let shape = crate::shape::sphere();
+        let i = Intersection {
+            t: 0.5,
+            object: &shape,
+        };
+        let comps = prepare_computations(&i, r);
+
+        let light = PointLight {
+            position: point(0, 0.25, 0),
+            intensity: Color::rgb(1., 1., 1.),
+        };
+
+        // sanity check: the eye is inside the sphere, looking back the way it came
+        assert!(comps.inside);
+        let expected =
+            shape
+                .material()
+                .lighting(light, comps.point, comps.eyev, comps.normalv, false);
+
+        assert_eq!(shade_hit(&comps, &light), expected);
+    }
+}
@@ -1,6 +1,54 @@
-/// Function to test for float equality
+/// Float equality with a single absolute epsilon breaks down for anything much larger than ~1.0,
+/// where legitimate rounding error (after a few matrix multiplies or a `sqrt`) exceeds
+/// `f64::EPSILON`. This combines an exact-equality short-circuit (also handles infinities and
+/// treats +0.0/-0.0 as equal), an absolute tolerance for values near zero where relative error is
+/// meaningless, a relative tolerance for everything else, and a ULPS check as a last resort for
+/// same-signed values that fall just outside the relative tolerance.
+///
+/// `eq_f64` is the sensible-default wrapper; use `eq_f64_tolerance` directly to tune the
+/// tolerances for a specific comparison.
 pub fn eq_f64(a: f64, b: f64) -> bool {
-    (a - b).abs() < f64::EPSILON
+    eq_f64_tolerance(a, b, 1e-12, 1e-9, 4)
+}
+
+/// See `eq_f64`. `abs_eps` bounds absolute error near zero, `rel_eps` bounds error relative to the
+/// larger operand's magnitude, and `max_ulps` is the largest allowed distance (in representable
+/// `f64` steps) between two same-signed, non-zero values once both tolerance checks fail.
+pub fn eq_f64_tolerance(a: f64, b: f64, abs_eps: f64, rel_eps: f64, max_ulps: i64) -> bool {
+    if a.is_nan() || b.is_nan() {
+        return false;
+    }
+
+    // Exact float equality (not a bit comparison): handles a == b, +0.0 == -0.0, and equal infinities.
+    if a == b {
+        return true;
+    }
+
+    // Infinities that aren't equal (e.g. +inf vs -inf) are never "close": the relative check
+    // below scales by `largest`, which is itself infinite once either operand is, and that
+    // makes an infinite `diff` compare `<=` against itself.
+    if a.is_infinite() || b.is_infinite() {
+        return false;
+    }
+
+    let diff = (a - b).abs();
+    if diff <= abs_eps {
+        return true;
+    }
+
+    let largest = a.abs().max(b.abs());
+    if diff <= rel_eps * largest {
+        return true;
+    }
+
+    // ULPS comparison only makes sense for values sharing a sign: bit patterns aren't monotonic
+    // across the sign boundary, and zero has no meaningful ULPS distance from a non-zero value.
+    if a.is_sign_negative() == b.is_sign_negative() && a != 0.0 && b != 0.0 {
+        let ulps = (a.to_bits() as i64 - b.to_bits() as i64).abs();
+        return ulps <= max_ulps;
+    }
+
+    false
 }
 
 #[cfg(test)]
@@ -11,4 +59,47 @@ mod tests {
     fn test_equal() {
         assert!(eq_f64(2_f64, 2_f64));
     }
+
+    #[test]
+    fn test_zero_signs_are_equal() {
+        assert!(eq_f64(0.0, -0.0));
+    }
+
+    #[test]
+    fn test_nan_is_never_equal() {
+        assert!(!eq_f64(f64::NAN, f64::NAN));
+        assert!(!eq_f64(f64::NAN, 1.0));
+    }
+
+    #[test]
+    fn test_equal_infinities() {
+        assert!(eq_f64(f64::INFINITY, f64::INFINITY));
+        assert!(!eq_f64(f64::INFINITY, f64::NEG_INFINITY));
+    }
+
+    #[test]
+    fn test_relative_tolerance_for_large_values() {
+        // exceeds f64::EPSILON in absolute terms, but is well within relative tolerance
+        assert!(eq_f64(123456789.123456, 123456789.123457));
+    }
+
+    #[test]
+    fn test_absolute_tolerance_near_zero() {
+        assert!(eq_f64(0.0, 1e-13));
+        assert!(!eq_f64(0.0, 1e-6));
+    }
+
+    #[test]
+    fn test_clearly_different_values_are_not_equal() {
+        assert!(!eq_f64(1.0, 2.0));
+        assert!(!eq_f64(1.0, -1.0));
+    }
+
+    #[test]
+    fn test_ulps_catches_values_just_outside_relative_tolerance() {
+        let a = 1.0_f64;
+        let b = f64::from_bits(a.to_bits() + 2);
+        assert!(eq_f64_tolerance(a, b, 0.0, 0.0, 4));
+        assert!(!eq_f64_tolerance(a, b, 0.0, 0.0, 1));
+    }
 }
@@ -1,6 +1,7 @@
 use crate::color::Color;
 use crate::tuple::Tuple;
 
+/// A light source with no size, emitting `intensity` equally in all directions from `position`.
 #[derive(Debug, Copy, Clone)]
 pub struct PointLight {
     pub position: Tuple,
@@ -16,6 +17,10 @@ impl PointLight {
     }
 }
 
+pub fn point_light(position: Tuple, intensity: Color) -> PointLight {
+    PointLight { position, intensity }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::tuple::point;
@@ -18,8 +18,10 @@ use crate::projectile::{Environment, Projectile};
 use crate::tuple::Tuple;
 
 mod assert_eqf64;
+mod camera;
 mod canvas;
 mod color;
+mod computations;
 mod eqf64;
 mod matrix;
 mod projectile;
@@ -5,6 +5,8 @@ use crate::color::Color;
 use crate::light::PointLight;
 use crate::tuple::Tuple;
 
+/// Surface properties used by the Phong reflection model: how much of a light's color a surface
+/// keeps in ambient, diffuse, and specular highlight terms, plus how tight the highlight is.
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Material {
     pub color: Color,
@@ -25,18 +27,26 @@ impl Material {
         }
     }
 
+    /// Phong shading: combines the material's ambient, diffuse, and specular terms for a single
+    /// point light, given the surface `position`, the eye direction `eyev`, and the surface
+    /// normal `normalv`. When `in_shadow` is true, the point is occluded from `light` and only
+    /// the ambient term (which doesn't depend on the light's direction) is returned.
     pub fn lighting(
         &self,
         light: PointLight,
         position: Tuple,
         eyev: Tuple,
         normalv: Tuple,
+        in_shadow: bool,
     ) -> Color {
         let effective_color = self.color * light.intensity;
+        let ambient = effective_color * self.ambient;
 
-        let lightv = (light.position - position).normalized();
+        if in_shadow {
+            return ambient;
+        }
 
-        let ambient = effective_color * self.ambient;
+        let lightv = (light.position - position).normalized();
 
         let (diffuse, specular);
 
@@ -59,6 +69,26 @@ impl Material {
 
         ambient + diffuse + specular
     }
+
+    /// Sums this material's `lighting` contribution from each of `lights` at `position`, so
+    /// callers with multiple light sources don't have to accumulate the per-light colors
+    /// themselves. `shadow_flags[i]` indicates whether `lights[i]` is occluded at `position`.
+    pub fn lighting_all(
+        &self,
+        lights: &[PointLight],
+        position: Tuple,
+        eyev: Tuple,
+        normalv: Tuple,
+        shadow_flags: &[bool],
+    ) -> Color {
+        assert_eq!(lights.len(), shadow_flags.len());
+
+        lights
+            .iter()
+            .zip(shadow_flags)
+            .map(|(&light, &in_shadow)| self.lighting(light, position, eyev, normalv, in_shadow))
+            .fold(Color::BLACK, |acc, c| acc + c)
+    }
 }
 
 pub fn material() -> Material {
@@ -95,7 +125,7 @@ mod tests {
             let normalv = vector(0, 0, -1);
             let light = point_light($lightpos, color(1, 1, 1));
 
-            let result = m.lighting(light, position, eyev, normalv);
+            let result = m.lighting(light, position, eyev, normalv, false);
             assert_eq!(result.round(5), $expected_color);
         };
     }
@@ -143,4 +173,52 @@ mod tests {
         // only ambient
         lighting_test!(eyev, lightpos, expected);
     }
+
+    #[test]
+    fn lighting_with_the_surface_in_shadow() {
+        let m = material();
+        let position = point(0, 0, 0);
+
+        let eyev = vector(0, 0, -1);
+        let normalv = vector(0, 0, -1);
+        let light = point_light(point(0, 0, -10), color(1, 1, 1));
+
+        let result = m.lighting(light, position, eyev, normalv, true);
+        assert_eq!(result, color(0.1, 0.1, 0.1));
+    }
+
+    #[test]
+    fn lighting_all_sums_contributions_from_multiple_lights() {
+        let m = material();
+        let position = point(0, 0, 0);
+        let eyev = vector(0, 0, -1);
+        let normalv = vector(0, 0, -1);
+
+        let light1 = point_light(point(0, 0, -10), color(1, 1, 1));
+        let light2 = point_light(point(0, 0, 10), color(1, 1, 1));
+
+        let expected = m.lighting(light1, position, eyev, normalv, false)
+            + m.lighting(light2, position, eyev, normalv, false);
+
+        let result = m.lighting_all(&[light1, light2], position, eyev, normalv, &[false, false]);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn lighting_all_skips_diffuse_and_specular_for_shadowed_lights() {
+        let m = material();
+        let position = point(0, 0, 0);
+        let eyev = vector(0, 0, -1);
+        let normalv = vector(0, 0, -1);
+
+        let visible = point_light(point(0, 0, -10), color(1, 1, 1));
+        let shadowed = point_light(point(0, 0, 10), color(1, 1, 1));
+
+        let expected = m.lighting(visible, position, eyev, normalv, false)
+            + m.lighting(shadowed, position, eyev, normalv, true);
+
+        let result =
+            m.lighting_all(&[visible, shadowed], position, eyev, normalv, &[false, true]);
+        assert_eq!(result, expected);
+    }
 }
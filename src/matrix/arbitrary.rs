@@ -0,0 +1,50 @@
+use proptest::prelude::*;
+use proptest::strategy::Strategy;
+
+use crate::matrix::Matrix;
+
+impl Matrix {
+    /// A `proptest` strategy generating random `size`-by-`size` matrices with elements drawn from
+    /// a bounded range, rejecting near-singular samples so invariants that go through `inverse()`
+    /// stay well-conditioned (an ill-conditioned sample would make the identity-recovery check
+    /// fail on floating-point noise alone, not a real bug).
+    pub fn arbitrary(size: usize) -> impl Strategy<Value = Matrix> {
+        let element = -10.0..10.0_f64;
+        proptest::collection::vec(proptest::collection::vec(element, size), size)
+            .prop_map(Matrix::from)
+            .prop_filter("near-singular matrix", |m| m.determinant().abs() > 1e-3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn inverting_a_random_matrix_recovers_the_identity(m in Matrix::arbitrary(4)) {
+            let product = (m.clone() * m.inverse()).map_elements(|e| (e * 100_000.0).round() / 100_000.0);
+            prop_assert_eq!(product, Matrix::identity(4));
+        }
+
+        #[test]
+        fn transposing_twice_returns_the_original_matrix(m in Matrix::arbitrary(4)) {
+            prop_assert_eq!(m.transpose().transpose(), m);
+        }
+
+        #[test]
+        fn determinant_of_a_product_is_the_product_of_determinants(
+            a in Matrix::arbitrary(4),
+            b in Matrix::arbitrary(4),
+        ) {
+            let lhs = (a.clone() * b.clone()).determinant();
+            let rhs = a.determinant() * b.determinant();
+            prop_assert!((lhs - rhs).abs() < 1e-3 * rhs.abs().max(1.0));
+        }
+
+        #[test]
+        fn determinant_is_invariant_under_transpose(m in Matrix::arbitrary(4)) {
+            prop_assert!((m.transpose().determinant() - m.determinant()).abs() < 1e-6);
+        }
+    }
+}
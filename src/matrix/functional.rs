@@ -18,6 +18,14 @@ impl Matrix {
 
         m
     }
+
+    /// Constructs a new matrix with each element rounded to `places` decimal places, for
+    /// comparing computed matrices against fixtures that only carry a handful of significant
+    /// digits.
+    pub fn round_elements(&self, places: i32) -> Matrix {
+        let fac = 10_f64.powi(places);
+        self.map_elements(|e| (e * fac).round() / fac)
+    }
 }
 
 
@@ -1,20 +1,37 @@
 use crate::assert_eqf64;
+use crate::matrix::scalar::Scalar;
 use crate::tuple::Tuple;
 
+#[cfg(test)]
+pub mod arbitrary;
 pub mod determinant;
+pub mod functional;
 pub mod operators;
-
+pub mod parsing;
+pub mod scalar;
+pub mod transformation;
+
+/// `T` is the element type a matrix is built from, defaulting to `f64` so every existing call
+/// site (`Matrix::new(...)`, `fn foo() -> Matrix`, ...) keeps compiling unchanged. Use
+/// `Matrix::<f32>` directly for the lower-precision, lower-memory option.
+///
+/// `elements` is a single flat, row-major buffer (`elements[row * width + col]`) rather than a
+/// `Vec<Vec<T>>`: one allocation and one contiguous scan per row instead of a pointer chase per
+/// row, which matters for the dot-product loop in `Mul for Matrix`.
 #[derive(Debug, Clone)]
-pub struct Matrix {
+pub struct Matrix<T = f64> {
     pub width: usize,
     pub height: usize,
-    pub elements: Vec<Vec<f64>>,
+    elements: Vec<T>,
 }
 
-impl Matrix {
+/// Alias for the matrix element type this crate actually renders with.
+pub type Matrixf = Matrix<f64>;
+
+impl<T: Scalar> Matrix<T> {
     /// Constructs a new matrix of given dimensions - all elements initialized to 0
     pub fn new(width: usize, height: usize) -> Self {
-        let elements: Vec<Vec<f64>> = vec![vec![0.; width]; height];
+        let elements = vec![T::zero(); width * height];
         Matrix {
             width,
             height,
@@ -23,14 +40,16 @@ impl Matrix {
     }
 
     /// Constructs a new matrix given the 2d-array of elements, assumed to be well-formed.
-    pub fn from(elements: Vec<Vec<f64>>) -> Self {
-        let height = elements.len();
-        let width = elements[0].len();
+    pub fn from(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows[0].len();
 
-        for row in &elements {
+        for row in &rows {
             assert_eq!(row.len(), width);
         }
 
+        let elements = rows.into_iter().flatten().collect();
+
         Matrix {
             width,
             height,
@@ -38,15 +57,11 @@ impl Matrix {
         }
     }
 
-    pub fn from_tuple(t: Tuple) -> Matrix {
-        Matrix::from(vec![vec![t.x, t.y, t.z, t.w]])
-    }
-
     /// Given a size s, return an s-by-s identity matrix (all 0 with 1s on the diagonal)
-    pub fn identity(s: usize) -> Matrix {
+    pub fn identity(s: usize) -> Self {
         let mut m = Matrix::new(s, s);
         for i in 0..s {
-            m.set(i, i, 1.0);
+            m.set(i, i, T::one());
         }
         m
     }
@@ -56,20 +71,25 @@ impl Matrix {
         (row < self.height) && (col < self.width)
     }
 
+    fn addr(&self, row: usize, col: usize) -> usize {
+        row * self.width + col
+    }
+
     /// Get a single value from the matrix given its:
     /// row (0-indexed from the top) and column (0-indexed from left)
-    pub fn get(&self, row: usize, col: usize) -> f64 {
+    pub fn get(&self, row: usize, col: usize) -> T {
         assert!(self.in_bounds(row, col));
-        self.elements[row][col]
+        self.elements[self.addr(row, col)]
     }
 
     /// Set a single element in the matrix given a row and column index
-    pub fn set(&mut self, row: usize, col: usize, element: f64) {
+    pub fn set(&mut self, row: usize, col: usize, element: T) {
         assert!(self.in_bounds(row, col));
-        self.elements[row][col] = element;
+        let addr = self.addr(row, col);
+        self.elements[addr] = element;
     }
 
-    pub fn transpose(&self) -> Matrix {
+    pub fn transpose(&self) -> Self {
         let mut m = Matrix::new(self.height, self.width);
 
         for i in 0..self.height {
@@ -89,7 +109,7 @@ mod tests {
     #[test]
     fn test_ctor() {
         let m1 = Matrix::from(vec![vec![0.]]);
-        let m2 = Matrix::new(1, 1);
+        let m2: Matrixf = Matrix::new(1, 1);
 
         assert_eq!(m1.width, m2.width);
         assert_eq!(m1.height, m2.height);
@@ -168,11 +188,19 @@ mod tests {
             z: 3.0,
             w: 1.0,
         };
-        assert_eq!((i * t), Matrix::from_tuple(t).transpose());
+        assert_eq!((i * t), t);
     }
 
     #[test]
     fn test_transpose_identity() {
-        assert_eq!(Matrix::identity(4), Matrix::identity(4).transpose());
+        assert_eq!(Matrix::<f64>::identity(4), Matrix::identity(4).transpose());
+    }
+
+    #[test]
+    fn test_f32_matrix() {
+        let m: Matrix<f32> = Matrix::from(vec![vec![1., 2.], vec![3., 4.]]);
+        assert_eq!(m.get(1, 0), 3.0_f32);
+        assert_eq!(m.transpose().get(0, 1), 3.0_f32);
+        assert_eq!(Matrix::<f32>::identity(2).get(1, 1), 1.0_f32);
     }
 }
@@ -1,13 +1,13 @@
 use std::ops::{Add, Div, Mul, Neg, Sub};
 
-use crate::eqf64::eq_f64;
+use crate::matrix::scalar::Scalar;
 use crate::matrix::Matrix;
 use crate::tuple::Tuple;
 
 /// This file has all the Operator implementations for Matrix
 /// Adding, subtracting, and multiplying both by another matrix as well as a Tuple (vector)
 
-impl PartialEq for Matrix {
+impl<T: Scalar> PartialEq for Matrix<T> {
     fn eq(&self, other: &Self) -> bool {
         let same_dims = self.width == other.width && self.height == other.height;
         if !same_dims {
@@ -16,7 +16,7 @@ impl PartialEq for Matrix {
 
         for row in 0..self.height {
             for col in 0..self.width {
-                if !eq_f64(self.get(row, col), other.get(row, col)) {
+                if !self.get(row, col).approx_eq(other.get(row, col)) {
                     return false;
                 }
             }
@@ -26,8 +26,8 @@ impl PartialEq for Matrix {
     }
 }
 
-impl Mul for Matrix {
-    type Output = Matrix;
+impl<T: Scalar> Mul for Matrix<T> {
+    type Output = Matrix<T>;
 
     fn mul(self, rhs: Self) -> Self::Output {
         let (a, b) = (self, rhs);
@@ -46,7 +46,7 @@ impl Mul for Matrix {
             for col in 0..result_width {
                 let dot = (0..common_dimension)
                     .map(|i| a.get(row, i) * b.get(i, col))
-                    .fold(0_f64, |a, b| a.add(b));
+                    .fold(T::zero(), |a, b| a.add(b));
 
                 m.set(row, col, dot);
             }
@@ -57,12 +57,33 @@ impl Mul for Matrix {
     }
 }
 
-impl Mul<Tuple> for Matrix {
-    type Output = Matrix;
+impl<T: Scalar> Mul<Tuple> for Matrix<T> {
+    type Output = Tuple;
 
     fn mul(self, rhs: Tuple) -> Self::Output {
-        let b = Matrix::from_tuple(rhs).transpose();
-        self * b
+        let b = Matrix::from(vec![vec![
+            T::from_f64(rhs.x),
+            T::from_f64(rhs.y),
+            T::from_f64(rhs.z),
+            T::from_f64(rhs.w),
+        ]])
+        .transpose();
+        let result = self * b;
+
+        Tuple {
+            x: result.get(0, 0).to_f64(),
+            y: result.get(1, 0).to_f64(),
+            z: result.get(2, 0).to_f64(),
+            w: result.get(3, 0).to_f64(),
+        }
+    }
+}
+
+impl<T: Scalar> Mul<&Tuple> for &Matrix<T> {
+    type Output = Tuple;
+
+    fn mul(self, rhs: &Tuple) -> Self::Output {
+        self.clone() * *rhs
     }
 }
 
@@ -132,8 +153,35 @@ mod tests {
             z: 33.0,
             w: 1.0,
         };
-        let exp = Matrix::from_tuple(exp).transpose();
 
+        assert_eq!(&m * &t, exp);
+        assert_eq!(m * t, exp);
+    }
+
+    #[test]
+    fn test_f32_matrix_transforms_a_tuple() {
+        let m: Matrix<f32> = Matrix::from(vec![
+            vec![1., 2., 3., 4.],
+            vec![2., 4., 4., 2.],
+            vec![8., 6., 4., 1.],
+            vec![0., 0., 0., 1.],
+        ]);
+
+        let t = Tuple {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+            w: 1.0,
+        };
+
+        let exp = Tuple {
+            x: 18.0,
+            y: 24.0,
+            z: 33.0,
+            w: 1.0,
+        };
+
+        assert_eq!(&m * &t, exp);
         assert_eq!(m * t, exp);
     }
 }
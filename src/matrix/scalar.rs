@@ -0,0 +1,119 @@
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::eqf64::eq_f64;
+
+/// The numeric element type a `Matrix` can be built from. `f64` (the default) is the only type
+/// the rest of the crate actually constructs, but `f32` is implemented too as the lower-precision
+/// option this trait exists to enable.
+pub trait Scalar:
+    Copy
+    + Clone
+    + Debug
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn abs(self) -> Self;
+
+    /// Approximate equality, used everywhere a `Matrix<T>` would otherwise compare elements with
+    /// `==` (`PartialEq`, determinant/invertibility checks): floating point rounding means exact
+    /// equality is rarely the right question.
+    fn approx_eq(self, other: Self) -> bool;
+
+    /// NaN-safe total ordering (mirrors `f64::total_cmp`/`f32::total_cmp`), for places that need a
+    /// deterministic order over possibly-NaN elements instead of `partial_cmp(...).unwrap()`,
+    /// which panics on NaN. See `Matrix::lu_decompose`'s pivot selection.
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    /// Converts a plain `f64` into this scalar type, bridging `Tuple` (always f64) into a
+    /// `Matrix<T>`.
+    fn from_f64(value: f64) -> Self;
+
+    /// The inverse of `from_f64`: converts this scalar back into a plain `f64`, used to pull a
+    /// `Tuple` back out of a `Matrix<T> * Tuple` result.
+    fn to_f64(self) -> f64;
+}
+
+impl Scalar for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn one() -> Self {
+        1.0
+    }
+
+    fn abs(self) -> Self {
+        f64::abs(self)
+    }
+
+    fn approx_eq(self, other: Self) -> bool {
+        eq_f64(self, other)
+    }
+
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        f64::total_cmp(self, other)
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+}
+
+impl Scalar for f32 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn one() -> Self {
+        1.0
+    }
+
+    fn abs(self) -> Self {
+        f32::abs(self)
+    }
+
+    fn approx_eq(self, other: Self) -> bool {
+        (self - other).abs() < 1e-5
+    }
+
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        f32::total_cmp(self, other)
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn f64_zero_and_one() {
+        assert_eq!(f64::zero(), 0.0);
+        assert_eq!(f64::one(), 1.0);
+    }
+
+    #[test]
+    fn f32_approx_eq_tolerates_rounding_error() {
+        assert!(1.0_f32.approx_eq(1.0 + f32::EPSILON));
+        assert!(!1.0_f32.approx_eq(1.1));
+    }
+}
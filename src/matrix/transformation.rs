@@ -4,7 +4,11 @@ use crate::matrix::Matrix;
 use crate::tuple::Tuple;
 
 impl Matrix {
-    /// Identity 4x4 matrix for transformations.
+    /// Identity 4x4 matrix for transformations, and the starting point for the fluent chain
+    /// below, e.g. `Matrix::transformation().rotate_z(PI / 2.).scale(5., 5., 5.).translate(10., 0., 0.)`
+    /// (transforms are applied right-to-left on a point, but this reads left-to-right in the
+    /// order they're meant to happen, since each builder call pre-multiplies itself onto the
+    /// accumulated matrix).
     pub(crate) fn transformation() -> Matrix {
         Matrix::from(vec![
             vec![1., 0., 0., 0.],
@@ -68,6 +72,40 @@ impl Matrix {
         ])
     }
 
+    /// Rotation about an arbitrary (normalizable) axis, by angle `r` radians, via Rodrigues'
+    /// rotation formula (mirrors cgmath's `from_axis_angle`). `rotation_x`/`rotation_y`/`rotation_z`
+    /// are just this with the principal unit vectors as the axis.
+    pub fn rotation_axis(axis: Tuple, r: f64) -> Matrix {
+        let axis = axis.normalized();
+        let (x, y, z) = (axis.x, axis.y, axis.z);
+        let (c, s) = (r.cos(), r.sin());
+        let t = 1. - c;
+
+        Matrix::from(vec![
+            vec![t * x * x + c, t * x * y - s * z, t * x * z + s * y, 0.],
+            vec![t * x * y + s * z, t * y * y + c, t * y * z - s * x, 0.],
+            vec![t * x * z - s * y, t * y * z + s * x, t * z * z + c, 0.],
+            vec![0., 0., 0., 1.],
+        ])
+    }
+
+    /// Builds the camera-orientation matrix for a view positioned at `from`, looking towards
+    /// `to`, with `up` indicating which way is "up" (mirrors cgmath's `look_at`).
+    pub fn view_transform(from: Tuple, to: Tuple, up: Tuple) -> Matrix {
+        let forward = (to - from).normalized();
+        let left = forward.cross(up.normalized());
+        let true_up = left.cross(forward);
+
+        let orientation = Matrix::from(vec![
+            vec![left.x, left.y, left.z, 0.],
+            vec![true_up.x, true_up.y, true_up.z, 0.],
+            vec![-forward.x, -forward.y, -forward.z, 0.],
+            vec![0., 0., 0., 1.],
+        ]);
+
+        orientation * Matrix::translation(-from.x, -from.y, -from.z)
+    }
+
     pub fn translate(self, x: f64, y: f64, z: f64) -> Self {
         Matrix::translation(x, y, z) * self
     }
@@ -88,9 +126,20 @@ impl Matrix {
         Matrix::rotation_z(r) * self
     }
 
+    pub fn rotate_axis(self, axis: Tuple, r: f64) -> Self {
+        Matrix::rotation_axis(axis, r) * self
+    }
+
     pub fn shear(self, xy: f64, xz: f64, yx: f64, yz: f64, zx: f64, zy: f64) -> Self {
         Matrix::shearing(xy, xz, yx, yz, zx, zy) * self
     }
+
+    /// Applies `other` after this transform, i.e. `self.then(&other) == other * self`. Lets a
+    /// pipeline built from arbitrary matrices (not just the named builders above) read in the
+    /// order the transforms are meant to happen, e.g. `rotation.then(&scaling).then(&translation)`.
+    pub fn then(self, other: &Matrix) -> Self {
+        other.clone() * self
+    }
 }
 
 #[cfg(test)]
@@ -272,4 +321,80 @@ mod tests {
             .translate(10.0, 5.0, 7.0);
         assert_eq!(t * p, Tuple::point(15.0, 0.0, 7.0));
     }
+
+    #[test]
+    fn then_chains_arbitrary_matrices_in_reading_order() {
+        // Same as `test_fluent_api_transformations`, but built with `then` from matrices that
+        // aren't necessarily the named translate/scale/rotate builders.
+        let p = Tuple::point(1.0, 0.0, 1.0);
+        let rotation = Matrix::rotation_x(PI / 2.0);
+        let scaling = Matrix::scaling(5.0, 5.0, 5.0);
+        let translation = Matrix::translation(10.0, 5.0, 7.0);
+
+        let t = rotation.then(&scaling).then(&translation);
+        assert_eq!(t * p, Tuple::point(15.0, 0.0, 7.0));
+    }
+
+    #[test]
+    fn the_transformation_matrix_for_the_default_orientation() {
+        let from = Tuple::point(0., 0., 0.);
+        let to = Tuple::point(0., 0., -1.);
+        let up = Tuple::vector(0., 1., 0.);
+
+        let t = Matrix::view_transform(from, to, up);
+        assert_eq!(t, Matrix::identity(4));
+    }
+
+    #[test]
+    fn a_view_transformation_matrix_looking_in_positive_z_direction() {
+        let from = Tuple::point(0., 0., 0.);
+        let to = Tuple::point(0., 0., 1.);
+        let up = Tuple::vector(0., 1., 0.);
+
+        let t = Matrix::view_transform(from, to, up);
+        assert_eq!(t, Matrix::scaling(-1., 1., -1.));
+    }
+
+    #[test]
+    fn the_view_transformation_moves_the_world() {
+        let from = Tuple::point(0., 0., 8.);
+        let to = Tuple::point(0., 0., 0.);
+        let up = Tuple::vector(0., 1., 0.);
+
+        let t = Matrix::view_transform(from, to, up);
+        assert_eq!(t, Matrix::translation(0., 0., -8.));
+    }
+
+    #[test]
+    fn an_arbitrary_view_transformation() {
+        let from = Tuple::point(1., 3., 2.);
+        let to = Tuple::point(4., -2., 8.);
+        let up = Tuple::vector(1., 1., 0.);
+
+        let t = Matrix::view_transform(from, to, up);
+        let expected = Matrix::from(vec![
+            vec![-0.50709, 0.50709, 0.67612, -2.36643],
+            vec![0.76772, 0.60609, 0.12122, -2.82843],
+            vec![-0.35857, 0.59761, -0.71714, 0.00000],
+            vec![0.00000, 0.00000, 0.00000, 1.00000],
+        ]);
+        assert_eq!(t.map_elements(|e| (e * 100000.0).round() / 100000.0), expected);
+    }
+
+    #[test]
+    fn rotation_axis_matches_the_principal_axis_rotations() {
+        let r = PI / 3.0;
+        assert_eq!(Matrix::rotation_axis(Tuple::vector(1., 0., 0.), r), Matrix::rotation_x(r));
+        assert_eq!(Matrix::rotation_axis(Tuple::vector(0., 1., 0.), r), Matrix::rotation_y(r));
+        assert_eq!(Matrix::rotation_axis(Tuple::vector(0., 0., 1.), r), Matrix::rotation_z(r));
+    }
+
+    #[test]
+    fn rotating_a_point_around_an_arbitrary_axis_leaves_points_on_the_axis_unchanged() {
+        let p = Tuple::point(1., 1., 1.);
+        let axis = Tuple::vector(1., 1., 1.);
+        let rotation = Matrix::rotation_axis(axis, 2.0 * PI / 3.0);
+
+        assert_eq!((rotation * p).round(5), p.round(5));
+    }
 }
@@ -2,6 +2,7 @@ use crate::assert_eqf64;
 use crate::matrix::Matrix;
 use crate::tuple::Tuple;
 
+/// A half-line cast from `origin` in `direction`, used to query the scene for intersections.
 #[derive(Debug, Copy, Clone)]
 pub struct Ray {
     pub origin: Tuple,
@@ -14,10 +15,12 @@ impl Ray {
         Ray { origin, direction }
     }
 
+    /// The point reached by travelling `t` units along the ray's direction from its origin.
     pub fn position(&self, t: f64) -> Tuple {
         self.origin + (self.direction * t)
     }
 
+    /// Applies `transform` to both the origin and direction, e.g. to move a ray into object space.
     pub fn transform(&self, transform: &Matrix) -> Ray {
         let origin = transform * &self.origin;
         let direction = transform * &self.direction;
@@ -9,6 +9,12 @@ use crate::tuple::Tuple;
 
 pub trait CanIntersect {
     fn transform(&self) -> &Matrix;
+
+    /// Deliberately returns the raw hit distances rather than `Intersections<'a>`: each concrete
+    /// type's impl (e.g. `Sphere::intersect`) only ever sees `&self` typed as that concrete type,
+    /// never as `&'a Shape`, so it has no `&'a Shape` to put in `Intersection::object`. Only
+    /// `Shape` itself, which owns a `&'a Shape` to `self`, can build `Intersection`s — that's
+    /// `Shape::intersections` below, which wraps this method.
     fn intersect(&self, ray: Ray) -> Vec<f64>;
     fn normal_at(&self, point: Tuple) -> Tuple;
     fn material(&self) -> Material;
@@ -20,30 +26,48 @@ pub struct Intersection<'a> {
     pub object: &'a Shape,
 }
 
+/// An ordered collection of an object's intersections along a single ray.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Intersections<'a>(pub Vec<Intersection<'a>>);
+
+impl<'a> Intersections<'a> {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// The visible intersection: the one with the lowest non-negative `t` (negative `t`s are
+    /// behind the ray's origin). `f64::total_cmp` gives us a NaN-free total order so this is a
+    /// single O(n) pass rather than a sort.
+    pub fn hit(&self) -> Option<&Intersection<'a>> {
+        self.0
+            .iter()
+            .filter(|i| i.t >= 0.0)
+            .min_by(|a, b| a.t.total_cmp(&b.t))
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub enum Shape {
     Sphere(Sphere),
 }
 
 impl Shape {
-    pub fn intersections(&self, ray: Ray) -> Vec<Intersection> {
-        self.intersect(ray)
+    pub fn intersections(&self, ray: Ray) -> Intersections {
+        let intersections = self
+            .intersect(ray)
             .iter()
             .map(|t| Intersection {
                 t: *t,
                 object: self,
             })
-            .collect()
-    }
-}
+            .collect();
 
-pub fn hit<'a>(intersections: &'a Vec<Intersection>) -> Option<&'a Intersection<'a>> {
-    intersections
-        .iter()
-        .filter(|i| i.t > 0.0)
-        .reduce(|a, b| if a.t < b.t { a } else { b })
-    // TODO can use f64::total_cmp once it's in stable:
-    //  .min_by(|a, b| a.t.total_cmp(&b.t))
+        Intersections(intersections)
+    }
 }
 
 pub fn sphere() -> Shape {
@@ -99,10 +123,10 @@ mod tests {
 
         // Test that intersection objects store references to the original shape
         let is = s.intersections(r);
-        assert_eqf64!(is[0].t, 4.0);
-        assert_eqf64!(is[1].t, 6.0);
-        assert_eq!(is[0].object, &s);
-        assert_eq!(is[1].object, &s);
+        assert_eqf64!(is.0[0].t, 4.0);
+        assert_eqf64!(is.0[1].t, 6.0);
+        assert_eq!(is.0[0].object, &s);
+        assert_eq!(is.0[1].object, &s);
     }
 
     #[test]
@@ -110,8 +134,8 @@ mod tests {
         let s = sphere();
         let ia = intersection(1, &s);
         let ib = intersection(2, &s);
-        let is = vec![ia.clone(), ib.clone()];
-        assert_eq!(hit(&is), Some(&ia));
+        let is = Intersections(vec![ia.clone(), ib.clone()]);
+        assert_eq!(is.hit(), Some(&ia));
     }
 
     #[test]
@@ -120,8 +144,8 @@ mod tests {
         let ia = intersection(-3, &s);
         let ib = intersection(50, &s);
         let ic = intersection(20, &s);
-        let is = vec![ia.clone(), ib.clone(), ic.clone()];
-        assert_eq!(hit(&is), Some(&ic));
+        let is = Intersections(vec![ia.clone(), ib.clone(), ic.clone()]);
+        assert_eq!(is.hit(), Some(&ic));
     }
 
     #[test]
@@ -129,7 +153,19 @@ mod tests {
         let s = sphere();
         let ia = intersection(-2, &s);
         let ib = intersection(-1, &s);
-        let is = vec![ia.clone(), ib.clone()];
-        assert_eq!(hit(&is), None);
+        let is = Intersections(vec![ia.clone(), ib.clone()]);
+        assert_eq!(is.hit(), None);
+    }
+
+    #[test]
+    fn test_intersections_len_and_is_empty() {
+        let s = sphere();
+        let is = Intersections(vec![intersection(1, &s), intersection(2, &s)]);
+        assert_eq!(is.len(), 2);
+        assert!(!is.is_empty());
+
+        let empty = Intersections(vec![]);
+        assert_eq!(empty.len(), 0);
+        assert!(empty.is_empty());
     }
 }
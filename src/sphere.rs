@@ -5,6 +5,7 @@ use crate::ray::Ray;
 use crate::shape::{CanIntersect, Shape};
 use crate::tuple::Tuple;
 
+/// A unit sphere centered at the origin in object space; `transform` maps it into world space.
 #[derive(Debug, PartialEq)]
 pub struct Sphere {
     transform: Matrix,
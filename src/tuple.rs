@@ -68,7 +68,23 @@ mod assert_vectors_tests {
 impl Tuple {
     /// Return a Vector's magnitude using Pythagoras' theorem.
     pub fn magnitude(&self) -> f64 {
-        (self.x.powi(2) + self.y.powi(2) + self.z.powi(2)).sqrt()
+        self.magnitude_squared().sqrt()
+    }
+
+    /// Magnitude without the final `sqrt`. Cheaper than `magnitude` when only ordering or a
+    /// squared comparison is needed (e.g. nearest-hit searches, radius checks).
+    pub fn magnitude_squared(&self) -> f64 {
+        self.x.powi(2) + self.y.powi(2) + self.z.powi(2)
+    }
+
+    /// Euclidean distance between this tuple and `other`.
+    pub fn distance(&self, other: Tuple) -> f64 {
+        self.distance_squared(other).sqrt()
+    }
+
+    /// Squared distance between this tuple and `other`, avoiding the `sqrt` in `distance`.
+    pub fn distance_squared(&self, other: Tuple) -> f64 {
+        (*self - other).magnitude_squared()
     }
 
     /// Dot product of this vector with another (defined as sum of products of each vector component)
@@ -116,10 +132,23 @@ impl Tuple {
         self.clone() / len
     }
 
+    /// Reflects this vector about `normal`, as used by `Material::lighting` to find the
+    /// specular highlight direction.
     pub fn reflect(&self, normal: Tuple) -> Self {
         *(self) - normal * 2.0 * (self.dot(normal))
     }
 
+    /// The component of this vector that lies along `other` (mirrors cgmath's `InnerSpace::project_on`).
+    pub fn project_on(&self, other: Tuple) -> Self {
+        other * (self.dot(other) / other.dot(other))
+    }
+
+    /// The component of this vector perpendicular to `other`, i.e. what's left after removing
+    /// `project_on(other)`.
+    pub fn reject_from(&self, other: Tuple) -> Self {
+        *self - self.project_on(other)
+    }
+
     pub fn origin() -> Tuple {
         Self::point(0., 0., 0.)
     }
@@ -227,6 +256,23 @@ mod tests {
         test(-v, expected);
     }
 
+    #[test]
+    fn test_magnitude_squared() {
+        let v = Tuple::vector(1., 2., 3.);
+        assert_eqf64!(v.magnitude_squared(), 14.);
+        assert_eqf64!(v.magnitude_squared(), v.magnitude().powi(2));
+    }
+
+    #[test]
+    fn test_distance_and_distance_squared() {
+        let a = Tuple::point(1., 2., 3.);
+        let b = Tuple::point(4., 6., 3.);
+
+        assert_eqf64!(a.distance_squared(b), 25.);
+        assert_eqf64!(a.distance(b), 5.);
+        assert_eqf64!(a.distance(b), a.distance_squared(b).sqrt());
+    }
+
     #[test]
     fn test_unit_vector() {
         fn test(vec: Tuple, expected: Tuple) {
@@ -281,4 +327,32 @@ mod tests {
         let n = vector(trt, trt, 0);
         assert_eq!(v.reflect(n).round(5), vector(1, 0, 0));
     }
+
+    #[test]
+    fn project_on_axis_aligned_vector() {
+        let v = vector(3, 4, 0);
+        let onto = vector(1, 0, 0);
+        assert_eq!(v.project_on(onto), vector(3, 0, 0));
+    }
+
+    #[test]
+    fn project_on_is_zero_for_perpendicular_vectors() {
+        let v = vector(0, 1, 0);
+        let onto = vector(1, 0, 0);
+        assert_eq!(v.project_on(onto), vector(0, 0, 0));
+    }
+
+    #[test]
+    fn reject_from_is_the_remainder_after_projecting() {
+        let v = vector(3, 4, 0);
+        let onto = vector(1, 0, 0);
+        assert_eq!(v.reject_from(onto), vector(0, 4, 0));
+    }
+
+    #[test]
+    fn project_and_reject_recompose_the_original_vector() {
+        let v = vector(3, 4, 5);
+        let onto = vector(1, 2, 0);
+        assert_eq!(v.project_on(onto) + v.reject_from(onto), v);
+    }
 }